@@ -19,6 +19,10 @@ pub(crate) fn target() -> Target {
         data_layout: "e-m:e-p270:32:32-p271:32:32-p272:64:64-i8:8:32-i16:16:32-i64:64-i128:128-n32:64-S128-Fn32".into(),
         arch: Arch::AArch64,
         options: TargetOptions {
+            // The Cortex-A57 is an Armv8.0-A core without LSE, so atomic read-modify-write
+            // operations (including the 128-bit ones) are lowered to `ldxr`/`stxr` and
+            // `ldxp`/`stxp` loops. Enabling `+lse` here would emit instructions the hardware
+            // does not implement.
             features: "+v8a,+neon,+crypto,+crc".into(),
             linker_flavor: LinkerFlavor::Gnu(Cc::No, Lld::Yes),
             linker: Some("rust-lld".into()),
@@ -1,5 +1,5 @@
 //@ add-minicore
-//@ revisions: x86_64 i686 aarch64
+//@ revisions: x86_64 i686 aarch64 aarch64_switch
 //@ assembly-output: emit-asm
 //@[x86_64] compile-flags: --target x86_64-unknown-linux-gnu -C llvm-args=-x86-asm-syntax=intel
 //@[x86_64] needs-llvm-components: x86
@@ -7,6 +7,8 @@
 //@[i686] needs-llvm-components: x86
 //@[aarch64] compile-flags: --target aarch64-unknown-linux-gnu
 //@[aarch64] needs-llvm-components: aarch64
+//@[aarch64_switch] compile-flags: --target aarch64-nintendo-switch-freestanding
+//@[aarch64_switch] needs-llvm-components: aarch64
 
 #![feature(no_core, lang_items)]
 #![crate_type = "lib"]
@@ -27,6 +29,7 @@
     // x86_64: sub rsp, 4096
     // i686: sub esp, 4096
     // aarch64: sub sp, sp, #1, lsl #12
+    // aarch64_switch: sub sp, sp, #1, lsl #12
     f(&mut [x; 8192]);
 }
 
@@ -37,5 +40,6 @@
     // x86_64: sub rsp, 4096
     // i686: sub esp, 4096
     // aarch64: sub sp, sp, #1, lsl #12
+    // aarch64_switch: sub sp, sp, #1, lsl #12
     f(&mut [x; 65536]);
 }